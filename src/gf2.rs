@@ -0,0 +1,231 @@
+// Dense binary-matrix (GF(2)) toolkit: rank, inverse, solve and RREF via
+// Gaussian elimination where every row operation is XOR. Rows are packed
+// into `u64` words so XOR-ing a pivot row into another row is a word-wise
+// loop instead of a bit-at-a-time one, which matters once matrices get wide.
+use ndarray::Array2;
+use numpy::{IntoPyArray, PyArray2, PyReadonlyArray2};
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::{pyfn, PyModule, PyResult, Python};
+
+/// A dense GF(2) matrix, rows packed `ceil(ncols / 64)` words wide.
+struct PackedMatrix {
+    rows: Vec<Vec<u64>>,
+    ncols: usize,
+}
+
+impl PackedMatrix {
+    fn from_bools(a: &ndarray::ArrayView2<bool>) -> Self {
+        let ncols = a.ncols();
+        let words_per_row = (ncols + 63) / 64;
+        let rows = a
+            .rows()
+            .into_iter()
+            .map(|row| {
+                let mut words = vec![0u64; words_per_row];
+                for (j, &bit) in row.iter().enumerate() {
+                    if bit {
+                        words[j / 64] |= 1u64 << (j % 64);
+                    }
+                }
+                words
+            })
+            .collect();
+        PackedMatrix { rows, ncols }
+    }
+
+    fn get(&self, row: usize, col: usize) -> bool {
+        self.rows[row][col / 64] & (1u64 << (col % 64)) != 0
+    }
+
+    fn xor_row_into(&mut self, src: usize, dst: usize) {
+        let (src_row, dst_row) = if src < dst {
+            let (left, right) = self.rows.split_at_mut(dst);
+            (left[src].clone(), &mut right[0])
+        } else {
+            let (left, right) = self.rows.split_at_mut(src);
+            (right[0].clone(), &mut left[dst])
+        };
+        for (d, s) in dst_row.iter_mut().zip(src_row.iter()) {
+            *d ^= s;
+        }
+    }
+
+    fn nrows(&self) -> usize {
+        self.rows.len()
+    }
+
+    fn to_array(&self) -> Array2<u8> {
+        Array2::from_shape_fn((self.nrows(), self.ncols), |(i, j)| {
+            self.get(i, j) as u8
+        })
+    }
+}
+
+/// Row-reduces `m` to reduced row-echelon form in place and returns the
+/// column index of each pivot found, in pivot-row order.
+fn rref(m: &mut PackedMatrix) -> Vec<usize> {
+    let mut pivot_cols = Vec::new();
+    let mut pivot_row = 0;
+    for col in 0..m.ncols {
+        if pivot_row >= m.nrows() {
+            break;
+        }
+        let Some(swap_row) = (pivot_row..m.nrows()).find(|&r| m.get(r, col)) else {
+            continue;
+        };
+        m.rows.swap(pivot_row, swap_row);
+        for r in 0..m.nrows() {
+            if r != pivot_row && m.get(r, col) {
+                m.xor_row_into(pivot_row, r);
+            }
+        }
+        pivot_cols.push(col);
+        pivot_row += 1;
+    }
+    pivot_cols
+}
+
+fn to_value_error(msg: &str) -> pyo3::PyErr {
+    PyValueError::new_err(msg.to_string())
+}
+
+pub fn register(_py: Python<'_>, m: &PyModule) -> PyResult<()> {
+    #[pyfn(m)]
+    fn gf2_rref<'py>(py: Python<'py>, a: PyReadonlyArray2<bool>) -> &'py PyArray2<u8> {
+        let mut packed = PackedMatrix::from_bools(&a.as_array());
+        rref(&mut packed);
+        packed.to_array().into_pyarray(py)
+    }
+
+    #[pyfn(m)]
+    fn gf2_rank(a: PyReadonlyArray2<bool>) -> usize {
+        let mut packed = PackedMatrix::from_bools(&a.as_array());
+        rref(&mut packed).len()
+    }
+
+    #[pyfn(m)]
+    fn gf2_inverse<'py>(py: Python<'py>, a: PyReadonlyArray2<bool>) -> PyResult<&'py PyArray2<u8>> {
+        let array = a.as_array();
+        let n = array.nrows();
+        if n != array.ncols() {
+            return Err(to_value_error("gf2_inverse: matrix must be square"));
+        }
+
+        // Augment [A | I] and reduce; if the left block doesn't become I,
+        // A is singular over GF(2).
+        let mut augmented = Array2::from_elem((n, 2 * n), false);
+        augmented.slice_mut(ndarray::s![.., ..n]).assign(&array);
+        for i in 0..n {
+            augmented[[i, n + i]] = true;
+        }
+        let mut packed = PackedMatrix::from_bools(&augmented.view());
+        let pivots = rref(&mut packed);
+        if pivots.len() != n || pivots != (0..n).collect::<Vec<_>>() {
+            return Err(to_value_error(
+                "gf2_inverse: matrix is singular over GF(2)",
+            ));
+        }
+
+        let inverse = Array2::from_shape_fn((n, n), |(i, j)| packed.get(i, n + j) as u8);
+        Ok(inverse.into_pyarray(py))
+    }
+
+    #[pyfn(m)]
+    fn gf2_solve<'py>(
+        py: Python<'py>,
+        a: PyReadonlyArray2<bool>,
+        b: PyReadonlyArray2<bool>,
+    ) -> PyResult<&'py PyArray2<u8>> {
+        let a_view = a.as_array();
+        let b_view = b.as_array();
+        let n = a_view.nrows();
+        if n != a_view.ncols() {
+            return Err(to_value_error("gf2_solve: `a` must be square"));
+        }
+        if b_view.nrows() != n {
+            return Err(to_value_error(
+                "gf2_solve: `a` and `b` must have the same number of rows",
+            ));
+        }
+
+        let k = b_view.ncols();
+        let mut augmented = Array2::from_elem((n, n + k), false);
+        augmented.slice_mut(ndarray::s![.., ..n]).assign(&a_view);
+        augmented.slice_mut(ndarray::s![.., n..]).assign(&b_view);
+        let mut packed = PackedMatrix::from_bools(&augmented.view());
+        let pivots = rref(&mut packed);
+        if pivots.len() != n || pivots != (0..n).collect::<Vec<_>>() {
+            return Err(to_value_error(
+                "gf2_solve: `a` is singular over GF(2), no unique solution",
+            ));
+        }
+
+        let solution = Array2::from_shape_fn((n, k), |(i, j)| packed.get(i, n + j) as u8);
+        Ok(solution.into_pyarray(py))
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{rref, PackedMatrix};
+    use ndarray::{array, Array2};
+
+    fn packed(rows: &[&[bool]]) -> PackedMatrix {
+        let ncols = rows[0].len();
+        let flat: Vec<bool> = rows.iter().flat_map(|r| r.iter().copied()).collect();
+        let a = Array2::from_shape_vec((rows.len(), ncols), flat).unwrap();
+        PackedMatrix::from_bools(&a.view())
+    }
+
+    #[test]
+    fn rref_identity_has_full_rank_and_is_unchanged() {
+        let mut m = packed(&[
+            &[true, false, false],
+            &[false, true, false],
+            &[false, false, true],
+        ]);
+        let pivots = rref(&mut m);
+        assert_eq!(pivots, vec![0, 1, 2]);
+        assert_eq!(m.to_array(), array![[1u8, 0, 0], [0, 1, 0], [0, 0, 1]]);
+    }
+
+    #[test]
+    fn rref_of_singular_matrix_has_deficient_rank() {
+        // Row 2 is row 0 XOR row 1, so rank should be 2, not 3.
+        let mut m = packed(&[
+            &[true, true, false],
+            &[false, true, true],
+            &[true, false, true],
+        ]);
+        let pivots = rref(&mut m);
+        assert_eq!(pivots.len(), 2);
+    }
+
+    #[test]
+    fn rref_eliminates_above_and_below_pivot() {
+        let mut m = packed(&[&[true, true], &[true, false]]);
+        rref(&mut m);
+        // [[1,1],[1,0]] reduces to the identity: row1 ^= row0 gives
+        // [[1,1],[0,1]], then row0 ^= row1 gives [[1,0],[0,1]].
+        assert_eq!(m.to_array(), array![[1u8, 0], [0, 1]]);
+    }
+
+    #[test]
+    fn inverse_round_trips_via_augmented_identity() {
+        // A = [[1,1],[0,1]] is its own inverse over GF(2): A * A = I.
+        let n = 2;
+        let a = array![[true, true], [false, true]];
+        let mut augmented = Array2::from_elem((n, 2 * n), false);
+        augmented.slice_mut(ndarray::s![.., ..n]).assign(&a);
+        for i in 0..n {
+            augmented[[i, n + i]] = true;
+        }
+        let mut m = PackedMatrix::from_bools(&augmented.view());
+        let pivots = rref(&mut m);
+        assert_eq!(pivots, vec![0, 1]);
+        let inverse = Array2::from_shape_fn((n, n), |(i, j)| m.get(i, n + j));
+        assert_eq!(inverse, a);
+    }
+}
@@ -1,6 +1,18 @@
 use ndarray;
-use numpy::{IntoPyArray, PyArray1, PyArray2, PyArrayDyn, PyReadonlyArrayDyn};
-use pyo3::prelude::{pymodule, PyModule, PyResult, Python};
+use numpy::{IntoPyArray, PyArray1, PyArray2, PyArrayDyn, PyReadonlyArrayDyn, PyUntypedArray};
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::{pymodule, PyModule, PyObject, PyResult, Python};
+
+mod gf2;
+mod linalg;
+mod owned_matrix;
+mod reductions;
+mod views;
+
+// Below this size (element count), the Rayon-parallel kernels fall back to a
+// plain serial loop: splitting into chunks and spinning up the thread pool
+// costs more than it saves for small arrays.
+const DEFAULT_MIN_PARALLEL_LEN: usize = 1 << 16;
 
 // NOTE
 // * numpy defaults to np.float64, if you use other type than f64 in Rust
@@ -16,18 +28,57 @@ fn rust_numpy_ext(_py: Python<'_>, m: &PyModule) -> PyResult<()> {
     // We are having the Python as input with a lifetime parameter.
     // Basically, none of the data that comes from Python can survive
     // longer than Python itself. Therefore, if Python is dropped, so must our Rust Python-dependent variables.
+    // `max_min` is monomorphized per dtype by this macro (and the
+    // `double_and_random_perturbation_*` variants by `impl_perturbation`
+    // below) instead of being written out four times. Each generated
+    // function still does numpy's usual dtype checking at the pyo3 boundary,
+    // so e.g. `max_min_f32` rejects a float64 array.
+    macro_rules! impl_max_min {
+        ($name:ident, $ty:ty) => {
+            #[pyfn(m)]
+            fn $name<'py>(py: Python<'py>, x: PyReadonlyArrayDyn<$ty>) -> &'py PyArray1<$ty> {
+                rust_fn::max_min(&x.as_array()).into_pyarray(py)
+            }
+        };
+    }
+    impl_max_min!(max_min_f64, f64);
+    impl_max_min!(max_min_f32, f32);
+    impl_max_min!(max_min_i64, i64);
+    impl_max_min!(max_min_i32, i32);
+
+    // Single entry point Python callers actually use: look at the incoming
+    // array's dtype and route to the matching monomorphized implementation
+    // above, so callers no longer have to cast to float64 themselves.
     #[pyfn(m)]
-    fn max_min<'py>(py: Python<'py>, x: PyReadonlyArrayDyn<f64>) -> &'py PyArray1<f64> {
-        // Here we have a numpy array of dynamic size. But we could restrict the
-        // function to only take arrays of certain size
-        // e.g. We could say PyReadonlyArray3 and only take 3 dim arrays.
-        // These functions will also do type checking so a
-        // numpy array of type np.float32 will not be accepted and will
-        // yield an Exception in Python as expected
-        let array = x.as_array();
-        let result_array = rust_fn::max_min(&array);
-        result_array.into_pyarray(py)
+    fn max_min(py: Python<'_>, x: &PyUntypedArray) -> PyResult<PyObject> {
+        let dtype = x.dtype();
+        if dtype.is_equiv_to(numpy::dtype::<f64>(py)) {
+            let arr = x.downcast::<PyArrayDyn<f64>>()?;
+            Ok(rust_fn::max_min(&arr.readonly().as_array())
+                .into_pyarray(py)
+                .into())
+        } else if dtype.is_equiv_to(numpy::dtype::<f32>(py)) {
+            let arr = x.downcast::<PyArrayDyn<f32>>()?;
+            Ok(rust_fn::max_min(&arr.readonly().as_array())
+                .into_pyarray(py)
+                .into())
+        } else if dtype.is_equiv_to(numpy::dtype::<i64>(py)) {
+            let arr = x.downcast::<PyArrayDyn<i64>>()?;
+            Ok(rust_fn::max_min(&arr.readonly().as_array())
+                .into_pyarray(py)
+                .into())
+        } else if dtype.is_equiv_to(numpy::dtype::<i32>(py)) {
+            let arr = x.downcast::<PyArrayDyn<i32>>()?;
+            Ok(rust_fn::max_min(&arr.readonly().as_array())
+                .into_pyarray(py)
+                .into())
+        } else {
+            Err(PyValueError::new_err(format!(
+                "max_min: unsupported dtype {dtype}"
+            )))
+        }
     }
+
     #[pyfn(m)]
     fn double_and_random_perturbation(
         _py: Python<'_>,
@@ -43,6 +94,39 @@ fn rust_numpy_ext(_py: Python<'_>, m: &PyModule) -> PyResult<()> {
         rust_fn::double_and_random_perturbation(&mut array, perturbation_scaling);
     }
 
+    // `f32`/`i64`/`i32` siblings of the function above, generated the same
+    // way as the `max_min_*` family.
+    macro_rules! impl_perturbation {
+        ($name:ident, $ty:ty) => {
+            #[pyfn(m)]
+            fn $name(_py: Python<'_>, x: &PyArrayDyn<$ty>, perturbation_scaling: f64) {
+                let mut array = unsafe { x.as_array_mut() };
+                rust_fn::double_and_random_perturbation(&mut array, perturbation_scaling);
+            }
+        };
+    }
+    impl_perturbation!(double_and_random_perturbation_f32, f32);
+    impl_perturbation!(double_and_random_perturbation_i64, i64);
+    impl_perturbation!(double_and_random_perturbation_i32, i32);
+
+    #[pyfn(m)]
+    #[pyo3(signature = (x, scaling, seed, min_parallel_len=DEFAULT_MIN_PARALLEL_LEN))]
+    fn double_and_random_perturbation_par(
+        py: Python<'_>,
+        x: &PyArrayDyn<f64>,
+        scaling: f64,
+        seed: u64,
+        min_parallel_len: usize,
+    ) {
+        let mut array = unsafe { x.as_array_mut() };
+
+        // Release the GIL for the duration of the compute so other Python
+        // threads can make progress while Rayon's workers are busy.
+        py.allow_threads(|| {
+            rust_fn::double_and_random_perturbation_par(&mut array, scaling, seed, min_parallel_len);
+        });
+    }
+
     #[pyfn(m)]
     fn eye<'py>(py: Python<'py>, size: usize) -> &PyArray2<f64> {
         // Simple demonstration of creating an ndarray inside Rust and return
@@ -50,6 +134,11 @@ fn rust_numpy_ext(_py: Python<'_>, m: &PyModule) -> PyResult<()> {
         array.into_pyarray(py)
     }
 
+    linalg::register(_py, m)?;
+    gf2::register(_py, m)?;
+    reductions::register(_py, m)?;
+    m.add_class::<owned_matrix::OwnedMatrix>()?;
+
     Ok(())
 }
 
@@ -58,10 +147,21 @@ fn rust_numpy_ext(_py: Python<'_>, m: &PyModule) -> PyResult<()> {
 // These are just some random operations
 // you probably want to do something more meaningful.
 mod rust_fn {
-    use ndarray::{arr1, Array1};
+    use ndarray::Array1;
+    use ndarray::Axis;
+    use ndarray::parallel::prelude::*;
+    use num_traits::NumCast;
     use numpy::ndarray::{ArrayViewD, ArrayViewMutD};
-    use ordered_float::OrderedFloat;
-    use rand::Rng;
+    use numpy::Element;
+    use rand::{Rng, SeedableRng};
+    use rand::rngs::SmallRng;
+
+    // Fixed number of chunks the parallel perturbation path splits into,
+    // independent of `rayon::current_num_threads()`: chunk boundaries (and
+    // therefore each chunk's seed) must only depend on the array's shape,
+    // or the "reproducible given a seed" guarantee breaks across machines
+    // and `RAYON_NUM_THREADS` settings.
+    const PAR_CHUNK_COUNT: usize = 64;
 
     // If we wanted to do something like this in python
     // we probably would want to generate matrices and add them
@@ -69,29 +169,82 @@ mod rust_fn {
     // matrices. And looping is usually painfully slow.
     // Rayon could be used here to run the mutation in parallel
     // this may be good for huge matrices
-    pub fn double_and_random_perturbation(x: &mut ArrayViewMutD<'_, f64>, scaling: f64) {
+    //
+    // Generic over any numpy dtype we bind (f64/f32/i64/i32): every element
+    // is round-tripped through f64 for the perturbation arithmetic and cast
+    // back. For the integer dtypes, a perturbed value that falls outside the
+    // target dtype's range (e.g. a large-magnitude `i64` after
+    // `*2 + noise`) saturates to that dtype's min/max instead of panicking,
+    // matching numpy's own integer overflow/cast behavior more closely than
+    // an `expect` would. An `f32` overflow becomes +/-inf, since the
+    // f64 -> f32 cast itself succeeds with an infinite value.
+    pub fn double_and_random_perturbation<T: Element + Copy + NumCast + num_traits::Bounded>(
+        x: &mut ArrayViewMutD<'_, T>,
+        scaling: f64,
+    ) {
         let mut rng = rand::thread_rng();
-        x.iter_mut()
-            .for_each(|x| *x = *x * 2. + (rng.gen::<f64>() - 0.5) * scaling);
+        x.iter_mut().for_each(|v| {
+            let as_f64: f64 = NumCast::from(*v).expect("element not representable as f64");
+            let perturbed = as_f64 * 2. + (rng.gen::<f64>() - 0.5) * scaling;
+            *v = NumCast::from(perturbed).unwrap_or(if perturbed > 0.0 {
+                T::max_value()
+            } else {
+                T::min_value()
+            });
+        });
+    }
+
+    // Same mutation as `double_and_random_perturbation`, but chunked across
+    // Rayon's thread pool once the element count passes `min_parallel_len`.
+    // Chunks are cut along axis 0 rather than requiring a contiguous
+    // buffer, so transposed views, strided slices (`a[:, ::2]`) and
+    // Fortran-ordered arrays all work -- not just C-contiguous ones. Each
+    // chunk gets its own `SmallRng`, seeded deterministically from `seed`
+    // and the chunk index; chunk boundaries come from a fixed chunk count,
+    // not the thread pool size, so the result is reproducible regardless of
+    // how many threads actually ran it or what `RAYON_NUM_THREADS` is set to.
+    pub fn double_and_random_perturbation_par(
+        x: &mut ArrayViewMutD<'_, f64>,
+        scaling: f64,
+        seed: u64,
+        min_parallel_len: usize,
+    ) {
+        if x.len() < min_parallel_len || x.ndim() == 0 {
+            let mut rng = SmallRng::seed_from_u64(seed);
+            x.iter_mut()
+                .for_each(|v| *v = *v * 2. + (rng.gen::<f64>() - 0.5) * scaling);
+            return;
+        }
+
+        let rows = x.len_of(Axis(0));
+        let chunk_rows = (rows / PAR_CHUNK_COUNT).max(1);
+        x.axis_chunks_iter_mut(Axis(0), chunk_rows)
+            .into_par_iter()
+            .enumerate()
+            .for_each(|(chunk_idx, mut chunk)| {
+                let mut rng = SmallRng::seed_from_u64(seed.wrapping_add(chunk_idx as u64));
+                chunk
+                    .iter_mut()
+                    .for_each(|v| *v = *v * 2. + (rng.gen::<f64>() - 0.5) * scaling);
+            });
     }
 
-    pub fn max_min(x: &ArrayViewD<'_, f64>) -> Array1<f64> {
-        if x.len() == 0 {
-            return arr1(&[]); // If the array has no elements, return empty array
+    // Generic over any bound numpy dtype. Note this orders purely by
+    // `PartialOrd`, so (unlike the old `OrderedFloat`-based float-only
+    // version) a NaN in a float array makes the outcome comparison-order
+    // dependent; `reduce(..., skipna=...)` is the NaN-aware replacement.
+    pub fn max_min<T: Element + Copy + PartialOrd>(x: &ArrayViewD<'_, T>) -> Array1<T> {
+        if x.is_empty() {
+            return Array1::from(Vec::new());
         }
-        let max_val = x
-            .iter()
-            .map(|a| OrderedFloat(*a))
-            .max()
-            .expect("Error calculating max value.")
-            .0;
-        let min_val = x
-            .iter()
-            .map(|a| OrderedFloat(*a))
-            .min()
-            .expect("Error calculating min value.")
-            .0;
-        let result_array = arr1(&[max_val, min_val]);
-        result_array
+        let mut iter = x.iter().copied();
+        let first = iter.next().expect("checked non-empty above");
+        let (max_val, min_val) = iter.fold((first, first), |(max_val, min_val), v| {
+            (
+                if v > max_val { v } else { max_val },
+                if v < min_val { v } else { min_val },
+            )
+        });
+        Array1::from(vec![max_val, min_val])
     }
 }
@@ -0,0 +1,91 @@
+// Linear-algebra bindings backed by `ndarray-linalg` (BLAS/LAPACK).
+//
+// These wrap the common dense solves so Python callers get an accelerated
+// path without round-tripping through `scipy`. Every entry point takes a
+// readonly numpy array, copies it into an owned `Array2`, and converts
+// `ndarray-linalg` errors into Python exceptions instead of panicking.
+use ndarray::Array2;
+use ndarray_linalg::error::LinalgError;
+use ndarray_linalg::{Cholesky, Eigh, Factorize, Inverse, LeastSquaresSvd, Solve, SVD, UPLO};
+use numpy::{IntoPyArray, PyArray1, PyArray2, PyReadonlyArray2};
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::{pyfn, PyModule, PyResult, Python};
+
+fn linalg_err(err: LinalgError) -> pyo3::PyErr {
+    PyValueError::new_err(format!("linear algebra error: {err}"))
+}
+
+pub fn register(_py: Python<'_>, m: &PyModule) -> PyResult<()> {
+    #[pyfn(m)]
+    fn solve<'py>(
+        py: Python<'py>,
+        a: PyReadonlyArray2<f64>,
+        b: PyReadonlyArray2<f64>,
+    ) -> PyResult<&'py PyArray2<f64>> {
+        let a: Array2<f64> = a.as_array().to_owned();
+        let b: Array2<f64> = b.as_array().to_owned();
+        // Factorize once and back-substitute per RHS column, instead of
+        // re-running a full LU factorization (O(n^3)) for every column.
+        let lu = a.factorize().map_err(linalg_err)?;
+        let mut result = Array2::zeros((a.nrows(), b.ncols()));
+        for (i, col) in b.columns().into_iter().enumerate() {
+            let solved = lu.solve(&col.to_owned()).map_err(linalg_err)?;
+            result.column_mut(i).assign(&solved);
+        }
+        Ok(result.into_pyarray(py))
+    }
+
+    #[pyfn(m)]
+    fn lstsq<'py>(
+        py: Python<'py>,
+        a: PyReadonlyArray2<f64>,
+        b: PyReadonlyArray2<f64>,
+    ) -> PyResult<&'py PyArray2<f64>> {
+        let a: Array2<f64> = a.as_array().to_owned();
+        let b: Array2<f64> = b.as_array().to_owned();
+        // SVD-based least squares (same approach numpy's `lstsq` uses):
+        // unlike the normal equations this doesn't square `a`'s condition
+        // number and still produces a minimum-norm solution when `a` is
+        // rank-deficient.
+        let result = a.least_squares(&b).map_err(linalg_err)?;
+        Ok(result.solution.into_pyarray(py))
+    }
+
+    #[pyfn(m)]
+    fn inv<'py>(py: Python<'py>, a: PyReadonlyArray2<f64>) -> PyResult<&'py PyArray2<f64>> {
+        let a: Array2<f64> = a.as_array().to_owned();
+        let inverse = a.inv().map_err(linalg_err)?;
+        Ok(inverse.into_pyarray(py))
+    }
+
+    #[pyfn(m)]
+    fn svd<'py>(
+        py: Python<'py>,
+        a: PyReadonlyArray2<f64>,
+    ) -> PyResult<(&'py PyArray2<f64>, &'py PyArray1<f64>, &'py PyArray2<f64>)> {
+        let a: Array2<f64> = a.as_array().to_owned();
+        let (u, s, vt) = a.svd(true, true).map_err(linalg_err)?;
+        let u = u.ok_or_else(|| PyValueError::new_err("SVD did not return U"))?;
+        let vt = vt.ok_or_else(|| PyValueError::new_err("SVD did not return V^T"))?;
+        Ok((u.into_pyarray(py), s.into_pyarray(py), vt.into_pyarray(py)))
+    }
+
+    #[pyfn(m)]
+    fn eigh<'py>(
+        py: Python<'py>,
+        a: PyReadonlyArray2<f64>,
+    ) -> PyResult<(&'py PyArray1<f64>, &'py PyArray2<f64>)> {
+        let a: Array2<f64> = a.as_array().to_owned();
+        let (vals, vecs) = a.eigh(UPLO::Lower).map_err(linalg_err)?;
+        Ok((vals.into_pyarray(py), vecs.into_pyarray(py)))
+    }
+
+    #[pyfn(m)]
+    fn cholesky<'py>(py: Python<'py>, a: PyReadonlyArray2<f64>) -> PyResult<&'py PyArray2<f64>> {
+        let a: Array2<f64> = a.as_array().to_owned();
+        let l = a.cholesky(UPLO::Lower).map_err(linalg_err)?;
+        Ok(l.into_pyarray(py))
+    }
+
+    Ok(())
+}
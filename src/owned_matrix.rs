@@ -0,0 +1,72 @@
+// Example `#[pyclass]` that keeps its data as a Rust-owned `Array2`, using
+// `views::as_mut_view`/`views::as_readonly` to hand it to Python without
+// copying. This is the pattern to copy for any future pyclass that wants
+// Python code to mutate its array data in place.
+use std::cell::Cell;
+
+use ndarray::Array2;
+use numpy::PyArray2;
+use pyo3::exceptions::PyRuntimeError;
+use pyo3::prelude::{pyclass, pymethods, PyAny, PyCell, PyResult, Python};
+use pyo3::AsPyPointer;
+
+use crate::views;
+
+#[pyclass]
+pub struct OwnedMatrix {
+    data: Array2<f64>,
+    // Tracks whether a mutable view handed out by `as_mut_view` is still
+    // outstanding. numpy keeps `self` alive for as long as a view into it
+    // exists, but nothing stops Python from minting a second view into the
+    // same buffer -- this flag is what turns that into an error instead of
+    // a silent aliasing bug.
+    mutable_view_live: Cell<bool>,
+}
+
+#[pymethods]
+impl OwnedMatrix {
+    #[new]
+    fn new(rows: usize, cols: usize) -> Self {
+        OwnedMatrix {
+            data: Array2::zeros((rows, cols)),
+            mutable_view_live: Cell::new(false),
+        }
+    }
+
+    /// A numpy view aliasing this object's buffer: `m.as_mut_view()[i, j] = v`
+    /// actually mutates `self.data`. Only one such view may be outstanding
+    /// at a time; call `release_mut_view` before requesting another.
+    fn as_mut_view<'py>(slf: &'py PyCell<Self>, py: Python<'py>) -> PyResult<&'py PyArray2<f64>> {
+        if slf.borrow().mutable_view_live.get() {
+            return Err(PyRuntimeError::new_err(
+                "as_mut_view: a mutable view onto this matrix is already live",
+            ));
+        }
+        slf.borrow().mutable_view_live.set(true);
+
+        // SAFETY: `owner` is `slf` itself, which numpy keeps alive (via its
+        // `base` pointer) for as long as the returned view is reachable
+        // from Python, so `self.data`'s buffer outlives the view. No other
+        // mutable view is live because we just checked and set the flag
+        // above.
+        let owner: &'py PyAny = unsafe { py.from_borrowed_ptr(slf.as_ptr()) };
+        Ok(unsafe { views::as_mut_view(&slf.borrow().data, owner) })
+    }
+
+    /// Marks the outstanding mutable view as released, allowing a new one
+    /// to be requested. This is advisory on the Rust side -- it does not
+    /// invalidate the numpy array Python already holds.
+    fn release_mut_view(&self) {
+        self.mutable_view_live.set(false);
+    }
+
+    /// A read-only numpy view aliasing this object's buffer, with
+    /// `WRITEABLE` actually cleared so Python assignment raises.
+    fn as_readonly<'py>(slf: &'py PyCell<Self>, py: Python<'py>) -> PyResult<&'py PyArray2<f64>> {
+        // SAFETY: same ownership argument as `as_mut_view`; a readonly view
+        // never conflicts with another readonly view, and clearing
+        // `WRITEABLE` means Python can no longer write through it.
+        let owner: &'py PyAny = unsafe { py.from_borrowed_ptr(slf.as_ptr()) };
+        Ok(unsafe { views::as_readonly(&slf.borrow().data, owner) })
+    }
+}
@@ -0,0 +1,252 @@
+// Axis-aware reductions with proper NaN semantics, replacing the old
+// flatten-everything `max_min`. `reduce(x, op, axis=None, skipna=False)` is
+// the drop-in accelerator for per-column statistics: min/max/sum/mean plus
+// their `arg` counterparts, computed globally or along one axis.
+use ndarray::{Array, ArrayD, ArrayViewD, Axis, IxDyn, Zip};
+use numpy::{IntoPyArray, PyReadonlyArrayDyn};
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::{pyfn, PyModule, PyObject, PyResult, Python};
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum ReduceOp {
+    Min,
+    Max,
+    ArgMin,
+    ArgMax,
+    Sum,
+    Mean,
+}
+
+impl ReduceOp {
+    fn parse(op: &str) -> PyResult<Self> {
+        match op {
+            "min" => Ok(ReduceOp::Min),
+            "max" => Ok(ReduceOp::Max),
+            "argmin" => Ok(ReduceOp::ArgMin),
+            "argmax" => Ok(ReduceOp::ArgMax),
+            "sum" => Ok(ReduceOp::Sum),
+            "mean" => Ok(ReduceOp::Mean),
+            other => Err(PyValueError::new_err(format!(
+                "reduce: unknown op {other:?}, expected one of min/max/argmin/argmax/sum/mean"
+            ))),
+        }
+    }
+
+    fn is_arg(self) -> bool {
+        matches!(self, ReduceOp::ArgMin | ReduceOp::ArgMax)
+    }
+}
+
+// First-occurrence-wins extremum index, matching numpy's argmin/argmax tie
+// break (`Iterator::max_by` keeps the *last* max on a tie, which is why this
+// isn't just `.enumerate().max_by(...)`).
+fn first_extreme_index<I: Iterator<Item = (usize, f64)>>(iter: I, want_max: bool) -> Option<usize> {
+    let mut best: Option<(usize, f64)> = None;
+    for (idx, val) in iter {
+        let is_better = match best {
+            None => true,
+            Some((_, best_val)) => {
+                if want_max {
+                    val > best_val
+                } else {
+                    val < best_val
+                }
+            }
+        };
+        if is_better {
+            best = Some((idx, val));
+        }
+    }
+    best.map(|(idx, _)| idx)
+}
+
+// Reduces over a NaN-free slice; callers have already handled the NaN
+// policy by this point.
+fn reduce_clean(values: &[f64], op: ReduceOp) -> f64 {
+    match op {
+        ReduceOp::Min => values.iter().copied().fold(f64::INFINITY, f64::min),
+        ReduceOp::Max => values.iter().copied().fold(f64::NEG_INFINITY, f64::max),
+        ReduceOp::Sum => values.iter().sum(),
+        ReduceOp::Mean => values.iter().sum::<f64>() / values.len() as f64,
+        ReduceOp::ArgMin | ReduceOp::ArgMax => {
+            first_extreme_index(values.iter().copied().enumerate(), op == ReduceOp::ArgMax)
+                .expect("lane is non-empty") as f64
+        }
+    }
+}
+
+// For min/max/argmin/argmax, `skipna` follows numpy's `nanmin`/`nanmax`
+// convention: NaNs are dropped, an all-NaN lane is NaN for min/max and an
+// error for argmin/argmax. `sum` instead follows `nansum` (an all-NaN, or
+// entirely empty, lane sums to `0.0`, not `NaN`). Without `skipna`, NaN
+// propagates like numpy's plain min/max/sum, with argmin/argmax reporting
+// the position of the first NaN.
+fn reduce_lane(lane: &[f64], op: ReduceOp, skipna: bool) -> PyResult<f64> {
+    if !skipna {
+        if op.is_arg() && lane.is_empty() {
+            let name = if op == ReduceOp::ArgMin { "argmin" } else { "argmax" };
+            return Err(PyValueError::new_err(format!(
+                "reduce: attempt to get {name} of an empty sequence"
+            )));
+        }
+        if let Some(nan_idx) = lane.iter().position(|v| v.is_nan()) {
+            return Ok(if op.is_arg() { nan_idx as f64 } else { f64::NAN });
+        }
+        return Ok(reduce_clean(lane, op));
+    }
+
+    let filtered: Vec<(usize, f64)> = lane
+        .iter()
+        .copied()
+        .enumerate()
+        .filter(|(_, v)| !v.is_nan())
+        .collect();
+    if filtered.is_empty() {
+        return match op {
+            ReduceOp::ArgMin | ReduceOp::ArgMax => Err(PyValueError::new_err(
+                "reduce: all-NaN slice encountered for argmin/argmax",
+            )),
+            ReduceOp::Sum => Ok(0.0),
+            _ => Ok(f64::NAN),
+        };
+    }
+
+    match op {
+        ReduceOp::ArgMin | ReduceOp::ArgMax => {
+            // Same first-occurrence tie-break as the non-skipna path above,
+            // just computed directly over the (original index, value)
+            // pairs instead of re-deriving the index from a matched value.
+            let idx = first_extreme_index(filtered.into_iter(), op == ReduceOp::ArgMax)
+                .expect("checked non-empty above");
+            Ok(idx as f64)
+        }
+        _ => {
+            let values: Vec<f64> = filtered.into_iter().map(|(_, v)| v).collect();
+            Ok(reduce_clean(&values, op))
+        }
+    }
+}
+
+fn reduce(
+    x: &ArrayViewD<'_, f64>,
+    op: ReduceOp,
+    axis: Option<usize>,
+    skipna: bool,
+) -> PyResult<ArrayD<f64>> {
+    let axis = match axis {
+        None => {
+            let flat: Vec<f64> = x.iter().copied().collect();
+            let val = reduce_lane(&flat, op, skipna)?;
+            return Ok(Array::from_elem(IxDyn(&[]), val));
+        }
+        Some(axis) if axis < x.ndim() => Axis(axis),
+        Some(axis) => {
+            return Err(PyValueError::new_err(format!(
+                "reduce: axis {axis} out of bounds for array with {} dims",
+                x.ndim()
+            )))
+        }
+    };
+
+    let out_shape: Vec<usize> = x
+        .shape()
+        .iter()
+        .enumerate()
+        .filter_map(|(i, &s)| (i != axis.index()).then_some(s))
+        .collect();
+    let mut out = Array::from_elem(IxDyn(&out_shape), 0.0_f64);
+    let mut first_err = None;
+    Zip::from(x.lanes(axis)).and(&mut out).for_each(|lane, out_val| {
+        if first_err.is_some() {
+            return;
+        }
+        let data: Vec<f64> = lane.iter().copied().collect();
+        match reduce_lane(&data, op, skipna) {
+            Ok(v) => *out_val = v,
+            Err(e) => first_err = Some(e),
+        }
+    });
+    match first_err {
+        Some(e) => Err(e),
+        None => Ok(out),
+    }
+}
+
+pub fn register(_py: Python<'_>, m: &PyModule) -> PyResult<()> {
+    #[pyfn(m)]
+    #[pyo3(signature = (x, op, axis=None, skipna=false))]
+    fn reduce<'py>(
+        py: Python<'py>,
+        x: PyReadonlyArrayDyn<f64>,
+        op: &str,
+        axis: Option<usize>,
+        skipna: bool,
+    ) -> PyResult<PyObject> {
+        let reduce_op = ReduceOp::parse(op)?;
+        let result = super::reductions::reduce(&x.as_array(), reduce_op, axis, skipna)?;
+        if reduce_op.is_arg() {
+            Ok(result.mapv(|v| v as i64).into_pyarray(py).into())
+        } else {
+            Ok(result.into_pyarray(py).into())
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{first_extreme_index, reduce_lane, ReduceOp};
+
+    #[test]
+    fn first_extreme_index_breaks_ties_first_occurrence() {
+        let values = [1.0, 3.0, 3.0, 2.0];
+        assert_eq!(
+            first_extreme_index(values.iter().copied().enumerate(), true),
+            Some(1)
+        );
+        let values = [3.0, 1.0, 1.0, 2.0];
+        assert_eq!(
+            first_extreme_index(values.iter().copied().enumerate(), false),
+            Some(1)
+        );
+    }
+
+    #[test]
+    fn argmax_agrees_with_and_without_skipna_on_ties() {
+        let lane = [1.0, 5.0, 5.0, f64::NAN];
+        // `skipna=true` drops the NaN; both paths must still pick the
+        // *first* occurrence of the tied maximum.
+        let with_skipna = reduce_lane(&lane, ReduceOp::ArgMax, true).unwrap();
+        let without_nan = reduce_lane(&lane[..3], ReduceOp::ArgMax, false).unwrap();
+        assert_eq!(with_skipna, 1.0);
+        assert_eq!(without_nan, 1.0);
+    }
+
+    #[test]
+    fn non_skipna_nan_propagates_to_first_nan_index() {
+        let lane = [1.0, f64::NAN, 5.0];
+        assert_eq!(reduce_lane(&lane, ReduceOp::Max, false).unwrap().is_nan(), true);
+        assert_eq!(reduce_lane(&lane, ReduceOp::ArgMax, false).unwrap(), 1.0);
+    }
+
+    #[test]
+    fn skipna_all_nan_is_nan_for_min_max_but_zero_for_sum() {
+        let lane = [f64::NAN, f64::NAN];
+        assert!(reduce_lane(&lane, ReduceOp::Min, true).unwrap().is_nan());
+        assert!(reduce_lane(&lane, ReduceOp::Max, true).unwrap().is_nan());
+        assert_eq!(reduce_lane(&lane, ReduceOp::Sum, true).unwrap(), 0.0);
+    }
+
+    #[test]
+    fn skipna_all_nan_argmax_errors_instead_of_panicking() {
+        let lane = [f64::NAN, f64::NAN];
+        assert!(reduce_lane(&lane, ReduceOp::ArgMax, true).is_err());
+    }
+
+    #[test]
+    fn empty_lane_argmin_errors_instead_of_panicking() {
+        let lane: [f64; 0] = [];
+        assert!(reduce_lane(&lane, ReduceOp::ArgMin, false).is_err());
+    }
+}
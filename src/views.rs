@@ -0,0 +1,52 @@
+// Zero-copy interop between Rust-owned `ndarray` data and numpy: unlike
+// `into_pyarray`, which always hands Python a fresh copy, these two helpers
+// build a `PyArray` that aliases a buffer a Rust object already owns.
+//
+// # Aliasing invariants
+// The returned array's `base` is set to `owner`, so numpy keeps `owner`
+// alive for as long as the view is reachable from Python -- that part is
+// safe by construction. What is NOT checked for you: these functions don't
+// stop Rust code from also holding a live reference into the same buffer,
+// or stop a caller from handing out two mutable views of the same data at
+// once. `owned_matrix::OwnedMatrix` shows the pattern we use to guard that:
+// track whether a mutable view is outstanding and refuse to mint a second
+// one until it's accounted for.
+use ndarray::{ArrayBase, Data, Dimension};
+use numpy::npyffi::NPY_ARRAY_WRITEABLE;
+use numpy::{Element, PyArray};
+use pyo3::prelude::PyAny;
+
+/// Build a `PyArray` sharing `array`'s buffer instead of copying it.
+/// `owner` should be the Python object that actually owns `array` (usually
+/// `self` of a `#[pyclass]` method); numpy records it as the array's
+/// `base`, keeping it alive as long as the view is reachable.
+///
+/// # Safety
+/// The caller must ensure `array`'s buffer outlives the returned `PyArray`
+/// (true as long as `owner` isn't dropped first) and must not hand out a
+/// second live mutable view onto the same buffer while this one is in use.
+pub unsafe fn as_mut_view<'py, A, S, D>(array: &ArrayBase<S, D>, owner: &'py PyAny) -> &'py PyArray<A, D>
+where
+    A: Element,
+    S: Data<Elem = A>,
+    D: Dimension,
+{
+    PyArray::borrow_from_array(array, owner)
+}
+
+/// Like `as_mut_view`, but the returned array's `WRITEABLE` flag is
+/// actually cleared, so `obj.field[i] = v` from Python raises instead of
+/// silently writing to (and then losing) a throwaway copy.
+///
+/// # Safety
+/// Same requirements as `as_mut_view`.
+pub unsafe fn as_readonly<'py, A, S, D>(array: &ArrayBase<S, D>, owner: &'py PyAny) -> &'py PyArray<A, D>
+where
+    A: Element,
+    S: Data<Elem = A>,
+    D: Dimension,
+{
+    let view = PyArray::borrow_from_array(array, owner);
+    (*view.as_array_ptr()).flags &= !NPY_ARRAY_WRITEABLE;
+    view
+}